@@ -0,0 +1,85 @@
+use std::collections::VecDeque;
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::card::Card;
+use crate::record::{self, GameEvent};
+
+/// One of the `n` draw/discard piles shared between players. A deck
+/// optionally owns a log writer so draws and discards that touch it are
+/// recorded from the deck's point of view as well as the player's. The
+/// writer isn't serializable and isn't part of a saved game; it's
+/// reattached when a checkpoint is resumed.
+#[derive(Serialize, Deserialize)]
+pub struct Deck {
+    pub number: usize,
+    pub cards: VecDeque<Card>,
+    #[serde(skip)]
+    pub log: Option<Box<dyn Write + Send>>,
+}
+
+impl Deck {
+    /// Creates an empty deck with no log writer attached.
+    pub fn new(number: usize) -> Deck {
+        Deck {
+            number,
+            cards: VecDeque::new(),
+            log: None,
+        }
+    }
+
+    /// Attaches a writer that every event touching this deck is appended
+    /// to, one line per event.
+    pub fn with_log(mut self, log: Box<dyn Write + Send>) -> Deck {
+        self.log = Some(log);
+        self
+    }
+
+    /// Records `event` to this deck's log under sequence number `seq`, if
+    /// it has one. `seq` must be the same value passed to every other log
+    /// this same event is also written to (e.g. the drawing player's), so
+    /// [`crate::record::merge`] can recognise them as one event rather
+    /// than two.
+    pub fn log_event(&mut self, seq: usize, event: &GameEvent) {
+        if let Some(log) = &mut self.log {
+            let _ = writeln!(log, "{}", record::format_line(seq, event));
+        }
+    }
+
+    /// Writes the deck's final contents to its log and flushes it. Called
+    /// once the game is over.
+    pub fn flush_final_contents(&mut self) {
+        if let Some(log) = &mut self.log {
+            let contents = self
+                .cards
+                .iter()
+                .map(|c| c.0.to_string())
+                .collect::<Vec<String>>()
+                .join(" ");
+            let _ = writeln!(log, "FINAL_DECK {} {}", self.number, contents);
+            let _ = log.flush();
+        }
+    }
+
+    /// A plain-data copy of this deck's contents, with no log attached.
+    /// Used to take a coherent snapshot for a [`crate::state::GameState`]
+    /// without handing out the live deck itself.
+    pub fn snapshot(&self) -> Deck {
+        Deck {
+            number: self.number,
+            cards: self.cards.clone(),
+            log: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for Deck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Deck")
+            .field("number", &self.number)
+            .field("cards", &self.cards)
+            .field("log", &self.log.is_some())
+            .finish()
+    }
+}
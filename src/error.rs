@@ -0,0 +1,43 @@
+use std::io;
+use std::num::ParseIntError;
+
+use thiserror::Error;
+
+/// Errors produced while reading the interactive setup prompts (player
+/// count, pack file) at the start of a game, saving or loading a
+/// checkpoint, or reading a run's logs back for `--verify`.
+#[derive(Debug, Error)]
+pub enum GameError {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("the number of players must be a positive integer! {0}")]
+    InvalidPlayerCount(ParseIntError),
+
+    #[error(
+        "the game must have at least two players, but was {0}! (with one player, the draw and \
+         discard deck are the same deck, which would deadlock on its own mutex)"
+    )]
+    TooFewPlayers(usize),
+
+    #[error("could not open file {path}! Because {source}.")]
+    OpenFile { path: String, source: io::Error },
+
+    #[error("could not read line {line}! Reason: {source}.")]
+    ReadLine { line: usize, source: io::Error },
+
+    #[error("could not parse \"{value}\" on line {line} as a possitive integer!")]
+    InvalidCardValue { value: String, line: usize },
+
+    #[error("a deck must have 8n ({expected}) cards, but the supplied deck had {actual}.")]
+    WrongPackSize { expected: usize, actual: usize },
+
+    #[error("could not parse \"{0}\" as an RNG seed! Seeds must be non-negative integers.")]
+    InvalidSeed(String),
+
+    #[error("could not read or write checkpoint: {0}")]
+    Checkpoint(#[from] serde_json::Error),
+
+    #[error("could not parse record log: {0}")]
+    Record(#[from] crate::record::ParseRecordError),
+}
@@ -1,82 +1,55 @@
-use rand::seq::SliceRandom;
-use std::collections::VecDeque;
-use std::fs::File;
-use std::io::{self, BufRead, BufReader};
-use std::path::Path;
+mod card;
+mod deck;
+mod error;
+mod pack;
+mod player;
+mod record;
+mod state;
+mod strategy;
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
-
-#[derive(Debug, PartialEq, Eq)]
-struct Card(usize);
-
-#[derive(Debug)]
-struct Deck {
-    number: usize,
-    cards: VecDeque<Card>,
-}
-
-#[derive(Debug)]
-struct Player<'a> {
-    number: usize,
-    draw_deck: &'a Arc<Mutex<Deck>>,
-    discard_deck: &'a Arc<Mutex<Deck>>,
-    hand: Vec<Card>,
-}
-
-impl<'a> Player<'a> {
-    fn has_winning_hand(&self) -> bool {
-        let winning = self.hand.windows(2).all(|w| w[0] == w[1]);
-        if winning {
-            println!("Player {} has won! 🥳  with hand {:?}", self.number, self.hand);
-        }
-        return winning;
+use std::thread;
+use std::time::Duration;
+
+use card::Card;
+use deck::Deck;
+use error::GameError;
+use pack::get_pack;
+use player::Player;
+use record::{merge, replay, Record};
+use state::GameState;
+use strategy::{DiscardStrategy, HoldMostCommon, LowestForeign, RandomDiscard};
+
+/// Builds the discard strategy and display name for player `i`, cycling
+/// through every strategy so a game with enough players showcases each
+/// one.
+fn strategy_for(i: usize) -> (Box<dyn DiscardStrategy>, String) {
+    match (i - 1) % 3 {
+        0 => (Box::new(RandomDiscard), "Random".to_string()),
+        1 => (Box::new(HoldMostCommon), "HoldMostCommon".to_string()),
+        _ => (Box::new(LowestForeign), "LowestForeign".to_string()),
     }
+}
 
-    fn select_discard_card(&self) -> Option<usize> {
-        let possibles = self
-            .hand
-            .iter()
-            .filter(|&c| c.0 != self.number)
-            .collect::<Vec<&Card>>();
-
-        match possibles.choose(&mut rand::thread_rng()).copied() {
-            Some(chosen) => self.hand.iter().position(|c| c == chosen),
-            None => None,
+/// Starting point for a game: either freshly dealt decks and hands, or a
+/// `GameState` checkpoint loaded from `--resume <path>`.
+fn starting_state(resume_path: Option<&str>) -> (usize, Vec<Deck>, Vec<Vec<Card>>) {
+    if let Some(path) = resume_path {
+        let state = GameState::load(path).expect("could not load checkpoint");
+        if state.player_count < 2 {
+            panic!("{}", GameError::TooFewPlayers(state.player_count));
         }
-    }
-
-    fn take_turn(&mut self) {
-        let mut draw_deck = self.draw_deck.lock().unwrap();
-        let new_card = match draw_deck.cards.pop_front() {
-            Some(nc) => nc,
-            None => {
-                println!("Player {}'s draw deck is empty!", self.number);
-                return;
-            } // draw deck is empty, end turn
-        };
         println!(
-            "Player {} drawns a {} from Deck {}",
-            self.number, new_card.0, draw_deck.number
+            "Resumed checkpoint with {} player(s) from {path}.",
+            state.player_count
         );
-
-        let discard_card = match self.select_discard_card() {
-            Some(discard_index) => {
-                let discard = self.hand.remove(discard_index);
-                self.hand.push(new_card);
-                discard
-            }
-            None => new_card,
-        };
-
-        let mut discard_deck = self.discard_deck.lock().unwrap();
-        println!(
-            "Player {} discards {} to Deck {}",
-            self.number, discard_card.0, discard_deck.number
-        );
-        discard_deck.cards.push_back(discard_card);
+        return (state.player_count, state.decks, state.hands);
     }
-}
 
-fn main() {
     let n: usize = loop {
         match get_n() {
             Ok(n) => break n,
@@ -91,105 +64,178 @@ fn main() {
         }
     };
 
-    let mut decks: Vec<Deck> = (1..=n)
-        .map(|i| Deck {
-            number: i,
-            cards: VecDeque::new(),
-        })
-        .collect();
-
-    // Deal cards to decks
+    let mut decks: Vec<Deck> = (1..=n).map(Deck::new).collect();
     for i in (4 * n)..(8 * n) {
         decks[i % n].cards.push_front(pack.remove(4 * n));
     }
 
-    let decks: Vec<Arc<Mutex<Deck>>> = decks.into_iter().map(|d| Arc::new(Mutex::new(d))).collect();
-
-    let mut players: Vec<Player> = (1..=n)
-        .map(|i| Player {
-            number: i,
-            draw_deck: &decks[i - 1],
-            discard_deck: &decks[(i) % n],
-            hand: Vec::new(),
-        })
-        .collect();
-
-    // Deal cards to players
+    let mut hands: Vec<Vec<Card>> = vec![Vec::new(); n];
     for i in (0..4 * n).rev() {
-        players[i % n].hand.push(
+        hands[i % n].push(
             pack.pop()
                 .expect("Pack is not full enough! Probably an index error."),
         );
     }
 
-    'game: loop {
-        for player in &mut players {
-            if player.has_winning_hand() {
-                break 'game;
+    (n, decks, hands)
+}
+
+/// Reads every `player_*.log` and `deck_*.log` file a single run of the
+/// game wrote into `dir` (see `main`'s `decks`/`players` setup), merges
+/// them into one globally ordered [`Record`], and replays it, printing the
+/// reconstructed final hands and decks. The replay panics if a discard
+/// names a card missing from that player's reconstructed hand; draws are
+/// not checked, since a deck's starting contents are not logged. Used by
+/// `--verify <dir>`.
+///
+/// A single log only ever shows one deck's or one player's side of each
+/// event, with no reliable interleaving across files, so verifying
+/// against one file in isolation isn't enough — every log the run wrote
+/// has to be merged by the sequence number embedded in each line.
+fn verify_log(dir: &str) -> Result<(), GameError> {
+    let mut records = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !((name.starts_with("player_") || name.starts_with("deck_")) && name.ends_with(".log")) {
+            continue;
+        }
+        let file = File::open(entry.path()).map_err(|source| GameError::OpenFile {
+            path: entry.path().display().to_string(),
+            source,
+        })?;
+        records.push(Record::from_reader(BufReader::new(file))?);
+    }
+
+    let record = merge(records);
+    let n = record.player_count();
+    let state = replay(&record, n);
+
+    println!("Verified {} event(s) for {n} player(s):", record.0.len());
+    for (i, hand) in state.hands.iter().enumerate() {
+        println!("  Player {} hand: {:?}", i + 1, hand);
+    }
+    for deck in &state.decks {
+        println!("  Deck {}: {:?}", deck.number, deck.cards);
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    if let Some(dir) = flag_value(&args, "--verify") {
+        if let Err(e) = verify_log(dir) {
+            println!("Could not verify log: {e}");
+        }
+        return;
+    }
+
+    let resume_path = flag_value(&args, "--resume");
+    let checkpoint_path = flag_value(&args, "--checkpoint").map(|p| p.to_string());
+
+    let (n, decks, hands) = starting_state(resume_path);
+
+    let decks: Vec<Arc<Mutex<Deck>>> = decks
+        .into_iter()
+        .map(|deck| {
+            let number = deck.number;
+            let log = File::create(format!("deck_{number}.log")).expect("could not create deck log");
+            Arc::new(Mutex::new(deck.with_log(Box::new(BufWriter::new(log)))))
+        })
+        .collect();
+
+    let hands: Vec<Arc<Mutex<Vec<Card>>>> = hands.into_iter().map(|h| Arc::new(Mutex::new(h))).collect();
+
+    // Shared by every player (and passed to their decks' `log_event` calls)
+    // so every event in the run gets a single, globally unique sequence
+    // number, letting `--verify` merge every log back into one true order.
+    let seq = Arc::new(AtomicUsize::new(0));
+
+    let players: Vec<Player> = (1..=n)
+        .map(|i| {
+            let log = File::create(format!("player_{i}.log")).expect("could not create player log");
+            let (strategy, name) = strategy_for(i);
+            Player {
+                number: i,
+                draw_deck: Arc::clone(&decks[i - 1]),
+                draw_deck_number: i,
+                discard_deck: Arc::clone(&decks[i % n]),
+                discard_deck_number: (i % n) + 1,
+                hand: Arc::clone(&hands[i - 1]),
+                log: Some(Box::new(BufWriter::new(log))),
+                name: Some(name),
+                strategy,
+                seq: Arc::clone(&seq),
             }
-            player.take_turn();
-            if player.has_winning_hand() {
-                break 'game;
+        })
+        .collect();
+
+    let winner = Arc::new(AtomicUsize::new(0));
+
+    let checkpoint_handle = checkpoint_path.map(|path| {
+        let decks = decks.clone();
+        let hands = hands.clone();
+        let winner = Arc::clone(&winner);
+        thread::spawn(move || loop {
+            let current_winner = winner.load(Ordering::SeqCst);
+            let state = GameState::snapshot(&decks, &hands, current_winner);
+            if let Err(e) = state.save(&path) {
+                println!("Could not write checkpoint: {e}");
             }
-        }
+            if current_winner != 0 {
+                break;
+            }
+            thread::sleep(Duration::from_millis(500));
+        })
+    });
+
+    let handles: Vec<_> = players
+        .into_iter()
+        .map(|player| {
+            let winner = Arc::clone(&winner);
+            thread::spawn(move || player.play(&winner))
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().expect("Player thread panicked");
+    }
+
+    if let Some(handle) = checkpoint_handle {
+        handle.join().expect("Checkpoint thread panicked");
+    }
+
+    for deck in &decks {
+        deck.lock().unwrap().flush_final_contents();
+    }
+
+    match winner.load(Ordering::SeqCst) {
+        0 => println!("The game ended with no winner."),
+        n => println!("Player {n} won the game!"),
     }
 }
 
-fn get_n() -> Result<usize, String> {
+/// Reads the value following a `--flag value` pair from the process
+/// arguments, if present.
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .map(|s| s.as_str())
+}
+
+fn get_n() -> Result<usize, GameError> {
     println!("Please enter the number of players:");
     let mut input = String::new();
-    io::stdin()
-        .read_line(&mut input)
-        .map_err(|e| e.to_string())?;
+    io::stdin().read_line(&mut input)?;
     let i = input
         .trim()
         .parse::<usize>()
-        .map_err(|e| format!("The number of players must be a positive integer! {e}"))?;
-    if i < 1 {
-        return Err(format!(
-            "The game must have a non-zero number of players, but was {}!",
-            i
-        ));
+        .map_err(GameError::InvalidPlayerCount)?;
+    if i < 2 {
+        return Err(GameError::TooFewPlayers(i));
     }
     return Ok(i);
 }
-
-fn get_pack(n: &usize) -> Result<Vec<Card>, String> {
-    println!("Please enter the location of the pack to load:");
-    let mut path_str = String::new();
-    io::stdin()
-        .read_line(&mut path_str)
-        .map_err(|e| e.to_string())?;
-    path_str = path_str
-        .trim()
-        .parse::<String>()
-        .map_err(|e| format!("Could not parse input file name string! {}.", e))?;
-    let path = Path::new(&path_str);
-    let file = File::open(&path)
-        .map_err(|e| format!("Could not open file {}! Because {}.", path.display(), e))?;
-
-    let reader = BufReader::new(&file);
-    let mut pack = Vec::new();
-
-    for (i, line) in reader.lines().enumerate() {
-        let line = line.map_err(|e| format!("Could not read line {}! Reason: {}.", i + 1, e))?;
-
-        let val = line.parse::<usize>().map_err(|_| {
-            format!(
-                "Could not parse \"{}\" on line {} as a possitive integer!",
-                line,
-                i + 1
-            )
-        })?;
-        pack.push(Card(val))
-    }
-
-    if pack.len() != 8 * n {
-        return Err(format!(
-            "A decks must have 8n ({}) cards, but the supplied deck had {}.",
-            8 * n,
-            pack.len()
-        ));
-    }
-    return Ok(pack);
-}
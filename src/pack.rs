@@ -0,0 +1,127 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::card::Card;
+use crate::error::GameError;
+
+/// Prompts for a pack, either loaded from a file on disk or freshly
+/// generated. A generated pack is requested by typing `generate`, with an
+/// optional trailing seed (e.g. `generate 42`) for reproducible packs.
+pub fn get_pack(n: &usize) -> Result<Vec<Card>, GameError> {
+    println!("Please enter the location of the pack to load, or \"generate\" to create one:");
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let input = input.trim();
+
+    if let Some(rest) = input.strip_prefix("generate") {
+        let seed = rest.trim();
+        let seed = if seed.is_empty() {
+            None
+        } else {
+            Some(
+                seed.parse::<u64>()
+                    .map_err(|_| GameError::InvalidSeed(seed.to_string()))?,
+            )
+        };
+        return Ok(generate_pack(*n, seed));
+    }
+
+    let path = Path::new(input);
+    let file = File::open(path).map_err(|e| GameError::OpenFile {
+        path: path.display().to_string(),
+        source: e,
+    })?;
+
+    let reader = BufReader::new(&file);
+    let mut pack = Vec::new();
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| GameError::ReadLine { line: i + 1, source: e })?;
+
+        let val = line
+            .parse::<usize>()
+            .map_err(|_| GameError::InvalidCardValue {
+                value: line.clone(),
+                line: i + 1,
+            })?;
+        pack.push(Card(val))
+    }
+
+    if pack.len() != 8 * n {
+        return Err(GameError::WrongPackSize {
+            expected: 8 * n,
+            actual: pack.len(),
+        });
+    }
+    Ok(pack)
+}
+
+/// Builds a valid, shuffled `8n`-card pack for an `n`-player game. At
+/// least four cards of each player's number are included so a winning
+/// hand of that value is always reachable, with the remainder padded by
+/// cycling through the player numbers `1..=n` again. Passing the same `seed`
+/// always produces the same pack, which is useful for reproducible tests
+/// and benchmarks; `None` shuffles with system randomness.
+pub fn generate_pack(n: usize, seed: Option<u64>) -> Vec<Card> {
+    let total = 8 * n;
+    let mut cards = Vec::with_capacity(total);
+
+    for player in 1..=n {
+        for _ in 0..4 {
+            cards.push(Card(player));
+        }
+    }
+
+    let mut padding_value = 1;
+    while cards.len() < total {
+        cards.push(Card(padding_value));
+        padding_value = (padding_value % n) + 1;
+    }
+
+    match seed {
+        Some(seed) => cards.shuffle(&mut StdRng::seed_from_u64(seed)),
+        None => cards.shuffle(&mut rand::thread_rng()),
+    }
+
+    cards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_pack_has_8n_cards() {
+        let pack = generate_pack(4, Some(1));
+        assert_eq!(pack.len(), 32);
+    }
+
+    #[test]
+    fn generated_pack_has_at_least_four_of_each_player_value() {
+        let n = 5;
+        let pack = generate_pack(n, Some(42));
+        for player in 1..=n {
+            let count = pack.iter().filter(|c| c.0 == player).count();
+            assert!(count >= 4, "player {player} has only {count} cards");
+        }
+    }
+
+    #[test]
+    fn same_seed_produces_the_same_pack() {
+        let a = generate_pack(4, Some(7));
+        let b = generate_pack(4, Some(7));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn different_seeds_usually_produce_different_packs() {
+        let a = generate_pack(4, Some(7));
+        let b = generate_pack(4, Some(8));
+        assert_ne!(a, b);
+    }
+}
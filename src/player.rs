@@ -0,0 +1,327 @@
+use std::io::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::card::Card;
+use crate::deck::Deck;
+use crate::record::{self, GameEvent};
+use crate::strategy::DiscardStrategy;
+
+pub struct Player {
+    pub number: usize,
+    pub draw_deck: Arc<Mutex<Deck>>,
+    pub draw_deck_number: usize,
+    pub discard_deck: Arc<Mutex<Deck>>,
+    pub discard_deck_number: usize,
+    // Shared (not just owned) so the main thread can read a consistent
+    // hand for a `GameState` checkpoint while this player keeps playing.
+    pub hand: Arc<Mutex<Vec<Card>>>,
+    pub log: Option<Box<dyn Write + Send>>,
+    pub name: Option<String>,
+    pub strategy: Box<dyn DiscardStrategy>,
+    // Shared across every player and deck in the run so every event gets
+    // a globally unique, monotonically increasing sequence number. Each
+    // draw and discard is logged twice (once from the deck's side, once from the
+    // player's), so the number has to be assigned once, here, and passed
+    // to both log writes — see `take_turn` — rather than let each log
+    // assign its own.
+    pub seq: Arc<AtomicUsize>,
+}
+
+impl Player {
+    /// How this player is identified in output: its number, plus its
+    /// strategy name if it has one.
+    fn label(&self) -> String {
+        match &self.name {
+            Some(name) => format!("Player {} ({})", self.number, name),
+            None => format!("Player {}", self.number),
+        }
+    }
+
+    /// Records `event` to this player's log under sequence number `seq`,
+    /// if it has one.
+    fn log_event(&mut self, seq: usize, event: &GameEvent) {
+        if let Some(log) = &mut self.log {
+            let _ = writeln!(log, "{}", record::format_line(seq, event));
+        }
+    }
+
+    fn has_winning_hand(&mut self) -> bool {
+        let hand = self.hand.lock().unwrap();
+        let winning = hand.windows(2).all(|w| w[0] == w[1]);
+        if winning {
+            println!("{} has won! 🥳  with hand {:?}", self.label(), *hand);
+            let event = GameEvent::Win {
+                player: self.number,
+                hand: hand.iter().map(|c| c.0).collect(),
+            };
+            drop(hand);
+            let seq = self.seq.fetch_add(1, Ordering::SeqCst);
+            self.log_event(seq, &event);
+        }
+        return winning;
+    }
+
+    fn take_turn(&mut self) {
+        // Locking both decks is pulled out into a free function, rather
+        // than a `&self` method, so the returned guards borrow only
+        // `self.draw_deck`/`self.discard_deck` instead of all of `self` —
+        // otherwise nothing below could take `&mut self` (e.g. to log an
+        // event) while the guards are alive.
+        let (mut draw_deck, mut discard_deck) = lock_decks(
+            &self.draw_deck,
+            self.draw_deck_number,
+            &self.discard_deck,
+            self.discard_deck_number,
+        );
+
+        let new_card = match draw_deck.cards.pop_front() {
+            Some(nc) => nc,
+            None => {
+                println!("{}'s draw deck is empty!", self.label());
+                return;
+            } // draw deck is empty, end turn
+        };
+        println!(
+            "{} drawns a {} from Deck {}",
+            self.label(),
+            new_card.0,
+            draw_deck.number
+        );
+        let draw_event = GameEvent::Draw {
+            player: self.number,
+            card: new_card.0,
+            from_deck: draw_deck.number,
+        };
+        let draw_seq = self.seq.fetch_add(1, Ordering::SeqCst);
+        draw_deck.log_event(draw_seq, &draw_event);
+
+        let discard_card = {
+            let mut hand = self.hand.lock().unwrap();
+            match self.strategy.select(&hand, self.number) {
+                Some(discard_index) => {
+                    let discard = hand.remove(discard_index);
+                    hand.push(new_card);
+                    discard
+                }
+                None => new_card,
+            }
+        };
+
+        println!(
+            "{} discards {} to Deck {}",
+            self.label(),
+            discard_card.0,
+            discard_deck.number
+        );
+        let discard_event = GameEvent::Discard {
+            player: self.number,
+            card: discard_card.0,
+            to_deck: discard_deck.number,
+        };
+        let discard_seq = self.seq.fetch_add(1, Ordering::SeqCst);
+        discard_deck.log_event(discard_seq, &discard_event);
+        discard_deck.cards.push_back(discard_card);
+
+        // Release both deck locks before touching `self` through a
+        // `&mut self` method: `log_event` needs exclusive access to the
+        // whole `Player`, which would otherwise conflict with the
+        // outstanding borrows of `self.draw_deck`/`self.discard_deck`
+        // still held by these guards.
+        drop(draw_deck);
+        drop(discard_deck);
+        self.log_event(draw_seq, &draw_event);
+        self.log_event(discard_seq, &discard_event);
+    }
+
+    /// Runs this player to completion on its own thread, stopping as soon
+    /// as it wins or another player's win is observed in `winner`.
+    pub fn play(mut self, winner: &AtomicUsize) {
+        let initial_hand = GameEvent::InitialHand {
+            player: self.number,
+            hand: self.hand.lock().unwrap().iter().map(|c| c.0).collect(),
+        };
+        let seq = self.seq.fetch_add(1, Ordering::SeqCst);
+        self.log_event(seq, &initial_hand);
+
+        loop {
+            if winner.load(Ordering::SeqCst) != 0 {
+                break;
+            }
+            if self.has_winning_hand() {
+                // Only the first player to actually reach a winning hand
+                // should be recorded as the winner: an unconditional
+                // `store` here would let whichever thread's write lands
+                // last overwrite an earlier, genuine winner.
+                let _ = winner.compare_exchange(0, self.number, Ordering::SeqCst, Ordering::SeqCst);
+                break;
+            }
+            self.take_turn();
+            if self.has_winning_hand() {
+                let _ = winner.compare_exchange(0, self.number, Ordering::SeqCst, Ordering::SeqCst);
+                break;
+            }
+        }
+
+        if let Some(log) = &mut self.log {
+            let _ = log.flush();
+        }
+    }
+}
+
+// Every turn takes the draw deck and the discard deck at once (the
+// discard happens while still holding the drawn card), so two player
+// threads whose draw/discard decks overlap must always acquire those
+// two mutexes in the same global order. Otherwise player i locking
+// deck i then deck i+1, while player i+1 locks deck i+1 then deck i+2,
+// can deadlock in a cycle. We always lock whichever deck has the lower
+// number first, then the other, with a plain blocking `.lock()` on both —
+// since every caller obeys that same ascending-number ordering, the
+// second lock can never be held by a thread that's stuck waiting on the
+// first, so there's nothing to deadlock against and no need to spin.
+//
+// This is a free function, not a `&self` method, so the returned guards
+// borrow only `draw_deck`/`discard_deck` rather than an entire `Player` —
+// letting callers still access other fields of `self` (or call `&mut
+// self` methods, once the guards are dropped) while these locks are held.
+//
+// Requires `draw_deck_number != discard_deck_number` (enforced by
+// rejecting single-player games in `main`): `draw_deck` and `discard_deck`
+// can otherwise be two `Arc` handles to the very same `Mutex`, and locking
+// it twice here would deadlock.
+fn lock_decks<'a>(
+    draw_deck: &'a Arc<Mutex<Deck>>,
+    draw_deck_number: usize,
+    discard_deck: &'a Arc<Mutex<Deck>>,
+    discard_deck_number: usize,
+) -> (MutexGuard<'a, Deck>, MutexGuard<'a, Deck>) {
+    if draw_deck_number < discard_deck_number {
+        let draw_deck = draw_deck.lock().unwrap();
+        let discard_deck = discard_deck.lock().unwrap();
+        (draw_deck, discard_deck)
+    } else {
+        let discard_deck = discard_deck.lock().unwrap();
+        let draw_deck = draw_deck.lock().unwrap();
+        (draw_deck, discard_deck)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::io;
+    use std::thread;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::record::{merge, replay, Record};
+    use crate::strategy::LowestForeign;
+
+    /// A `Write` that appends into a shared buffer, so a test can keep
+    /// reading what was logged after handing a boxed clone of it to a
+    /// `Player`/`Deck`.
+    struct SharedLog(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedLog {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn record_of(buf: &Arc<Mutex<Vec<u8>>>) -> Record {
+        Record::from_reader(buf.lock().unwrap().as_slice()).unwrap()
+    }
+
+    #[test]
+    fn lock_decks_orders_by_deck_number_regardless_of_draw_discard_role() {
+        let deck_1 = Arc::new(Mutex::new(Deck::new(1)));
+        let deck_2 = Arc::new(Mutex::new(Deck::new(2)));
+
+        let (draw, discard) = lock_decks(&deck_1, 1, &deck_2, 2);
+        assert_eq!(draw.number, 1);
+        assert_eq!(discard.number, 2);
+        drop((draw, discard));
+
+        // Same pair, roles reversed: deck 1 must still be locked first.
+        // While deck 1 is held here, a correctly ordered call blocks on it
+        // without ever touching deck 2.
+        let held = deck_1.lock().unwrap();
+        let (d1, d2) = (Arc::clone(&deck_1), Arc::clone(&deck_2));
+        let handle = thread::spawn(move || {
+            let (draw, discard) = lock_decks(&d2, 2, &d1, 1);
+            (draw.number, discard.number)
+        });
+        thread::sleep(Duration::from_millis(50));
+        assert!(deck_2.try_lock().is_ok());
+        drop(held);
+        assert_eq!(handle.join().unwrap(), (2, 1));
+    }
+
+    #[test]
+    fn compare_exchange_keeps_the_first_winner() {
+        let winner = AtomicUsize::new(0);
+        assert!(winner
+            .compare_exchange(0, 1, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok());
+        // A second, later winner must not be able to overwrite the first.
+        assert!(winner
+            .compare_exchange(0, 2, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err());
+        assert_eq!(winner.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn take_turn_logs_events_that_merge_and_replay_back_to_a_consistent_state() {
+        let draw_log = Arc::new(Mutex::new(Vec::new()));
+        let discard_log = Arc::new(Mutex::new(Vec::new()));
+        let player_log = Arc::new(Mutex::new(Vec::new()));
+
+        let draw_deck = Arc::new(Mutex::new(Deck {
+            number: 1,
+            cards: VecDeque::from(vec![Card(1)]),
+            log: Some(Box::new(SharedLog(Arc::clone(&draw_log)))),
+        }));
+        let discard_deck = Arc::new(Mutex::new(Deck {
+            number: 2,
+            cards: VecDeque::new(),
+            log: Some(Box::new(SharedLog(Arc::clone(&discard_log)))),
+        }));
+        let hand = Arc::new(Mutex::new(vec![Card(3), Card(1)]));
+
+        let mut player = Player {
+            number: 1,
+            draw_deck: Arc::clone(&draw_deck),
+            draw_deck_number: 1,
+            discard_deck: Arc::clone(&discard_deck),
+            discard_deck_number: 2,
+            hand: Arc::clone(&hand),
+            log: Some(Box::new(SharedLog(Arc::clone(&player_log)))),
+            name: None,
+            strategy: Box::new(LowestForeign),
+            seq: Arc::new(AtomicUsize::new(0)),
+        };
+
+        // Log the starting hand the same way `play` does, so the record
+        // has somewhere for the turn's discard to have come from.
+        let initial_hand = GameEvent::InitialHand { player: 1, hand: vec![3, 1] };
+        let seq = player.seq.fetch_add(1, Ordering::SeqCst);
+        player.log_event(seq, &initial_hand);
+
+        // LowestForeign keeps the card matching its own number (1) and
+        // discards the other (3), so after drawing the deck's only card
+        // (another 1) the hand becomes [1, 1]: a win.
+        player.take_turn();
+        assert!(player.has_winning_hand());
+
+        let merged = merge(vec![record_of(&draw_log), record_of(&discard_log), record_of(&player_log)]);
+        let n = merged.player_count();
+        let state = replay(&merged, n);
+
+        assert_eq!(state.hands[0], vec![Card(1), Card(1)]);
+        assert_eq!(state.decks[1].cards, vec![Card(3)]);
+    }
+}
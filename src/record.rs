@@ -0,0 +1,327 @@
+use std::io::BufRead;
+
+use thiserror::Error;
+
+use crate::card::Card;
+use crate::deck::Deck;
+
+/// A single thing that happened during a game, in the order it happened.
+/// Players and decks each emit these into their own log file so a run can
+/// be inspected or replayed later instead of only ever being visible as
+/// `println!` output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameEvent {
+    InitialHand { player: usize, hand: Vec<usize> },
+    Draw { player: usize, card: usize, from_deck: usize },
+    Discard { player: usize, card: usize, to_deck: usize },
+    Win { player: usize, hand: Vec<usize> },
+}
+
+impl GameEvent {
+    /// Renders this event as a single line of the record format, the
+    /// inverse of [`GameEvent::parse_line`].
+    pub fn to_line(&self) -> String {
+        match self {
+            GameEvent::InitialHand { player, hand } => {
+                format!("INITIAL_HAND {player} {}", join_cards(hand))
+            }
+            GameEvent::Draw { player, card, from_deck } => {
+                format!("DRAW {player} {card} {from_deck}")
+            }
+            GameEvent::Discard { player, card, to_deck } => {
+                format!("DISCARD {player} {card} {to_deck}")
+            }
+            GameEvent::Win { player, hand } => {
+                format!("WIN {player} {}", join_cards(hand))
+            }
+        }
+    }
+
+    /// Parses a single line of the record format back into a `GameEvent`.
+    fn parse_line(line: &str) -> Result<GameEvent, ParseRecordError> {
+        let mut tokens = line.split_whitespace();
+        let action = tokens
+            .next()
+            .ok_or_else(|| ParseRecordError::FailedToParseLine(line.to_string()))?;
+
+        let parse_usize = |s: &str| -> Result<usize, ParseRecordError> {
+            s.parse::<usize>().map_err(|_| ParseRecordError::InvalidCardValue)
+        };
+
+        match action {
+            "INITIAL_HAND" => {
+                let player = parse_usize(
+                    tokens
+                        .next()
+                        .ok_or_else(|| ParseRecordError::FailedToParseLine(line.to_string()))?,
+                )?;
+                let hand = tokens.map(parse_usize).collect::<Result<Vec<usize>, _>>()?;
+                Ok(GameEvent::InitialHand { player, hand })
+            }
+            "DRAW" => {
+                let values = tokens.map(parse_usize).collect::<Result<Vec<usize>, _>>()?;
+                match values.as_slice() {
+                    [player, card, from_deck] => Ok(GameEvent::Draw {
+                        player: *player,
+                        card: *card,
+                        from_deck: *from_deck,
+                    }),
+                    _ => Err(ParseRecordError::FailedToParseLine(line.to_string())),
+                }
+            }
+            "DISCARD" => {
+                let values = tokens.map(parse_usize).collect::<Result<Vec<usize>, _>>()?;
+                match values.as_slice() {
+                    [player, card, to_deck] => Ok(GameEvent::Discard {
+                        player: *player,
+                        card: *card,
+                        to_deck: *to_deck,
+                    }),
+                    _ => Err(ParseRecordError::FailedToParseLine(line.to_string())),
+                }
+            }
+            "WIN" => {
+                let player = parse_usize(
+                    tokens
+                        .next()
+                        .ok_or_else(|| ParseRecordError::FailedToParseLine(line.to_string()))?,
+                )?;
+                let hand = tokens.map(parse_usize).collect::<Result<Vec<usize>, _>>()?;
+                Ok(GameEvent::Win { player, hand })
+            }
+            _ => Err(ParseRecordError::UnknownAction),
+        }
+    }
+}
+
+fn join_cards(cards: &[usize]) -> String {
+    cards
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Renders `event` as a single record-format line prefixed with `seq`, the
+/// inverse of [`parse_line`]. The same event is written to both a deck's
+/// and a player's log, each from its own point of view; passing the same
+/// `seq` to both writes is what lets [`merge`] later recognise them as the
+/// same logical event instead of two.
+pub fn format_line(seq: usize, event: &GameEvent) -> String {
+    format!("{seq} {}", event.to_line())
+}
+
+/// Parses a single `seq`-prefixed line written by [`format_line`] back into
+/// its sequence number and event.
+fn parse_line(line: &str) -> Result<(usize, GameEvent), ParseRecordError> {
+    let (seq, rest) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseRecordError::FailedToParseLine(line.to_string()))?;
+    let seq = seq
+        .parse::<usize>()
+        .map_err(|_| ParseRecordError::FailedToParseLine(line.to_string()))?;
+    let event = GameEvent::parse_line(rest)?;
+    Ok((seq, event))
+}
+
+/// Errors produced while parsing a game log back into [`GameEvent`]s.
+#[derive(Debug, Error)]
+pub enum ParseRecordError {
+    #[error("failed to parse record line: {0}")]
+    FailedToParseLine(String),
+    #[error("invalid card value in record line")]
+    InvalidCardValue,
+    #[error("unknown action in record line")]
+    UnknownAction,
+}
+
+/// A parsed game log: every `(seq, event)` pair a player or deck wrote out
+/// while a game was in progress, in the order the lines were read.
+#[derive(Debug, Clone, Default)]
+pub struct Record(pub Vec<(usize, GameEvent)>);
+
+impl Record {
+    /// Reads a record log file back into a `Record`, one `(seq, event)`
+    /// pair per line. Blank lines and the `FINAL_DECK` summary line that
+    /// [`Deck::flush_final_contents`] appends to a deck log carry no event
+    /// and are skipped.
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Record, ParseRecordError> {
+        let mut events = Vec::new();
+        for line in reader.lines() {
+            let line = line.map_err(|e| ParseRecordError::FailedToParseLine(e.to_string()))?;
+            if line.trim().is_empty() || line.starts_with("FINAL_DECK") {
+                continue;
+            }
+            events.push(parse_line(&line)?);
+        }
+        Ok(Record(events))
+    }
+
+    /// The number of players referenced by this record, i.e. the highest
+    /// player or deck number seen in any event. A record built from a
+    /// single player's log only ever has that player's own number in its
+    /// `player` field, so deck numbers (which a player's draws and
+    /// discards can reference freely) have to be considered too. Used to
+    /// size up a [`replay`] when the player count isn't known up front.
+    pub fn player_count(&self) -> usize {
+        self.0
+            .iter()
+            .map(|(_, event)| match event {
+                GameEvent::InitialHand { player, .. } => *player,
+                GameEvent::Draw { player, from_deck, .. } => (*player).max(*from_deck),
+                GameEvent::Discard { player, to_deck, .. } => (*player).max(*to_deck),
+                GameEvent::Win { player, .. } => *player,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Merges the per-player and per-deck logs of a single run into one
+/// globally ordered `Record`. Every draw and discard is written twice —
+/// once to the deck it touched and once to the player it touched — under
+/// the same `seq`, while an initial hand or a win is written only to the
+/// player's log, so merging is: concatenate, sort by `seq`, then drop the
+/// duplicate a shared `seq` leaves behind.
+pub fn merge(records: Vec<Record>) -> Record {
+    let mut events: Vec<(usize, GameEvent)> = records.into_iter().flat_map(|r| r.0).collect();
+    events.sort_by_key(|(seq, _)| *seq);
+    events.dedup_by_key(|(seq, _)| *seq);
+    Record(events)
+}
+
+/// The reconstructed state of a game after replaying a [`Record`].
+#[derive(Debug)]
+pub struct ReplayState {
+    pub decks: Vec<Deck>,
+    pub hands: Vec<Vec<Card>>,
+}
+
+/// Reconstructs deck and hand state by deterministically replaying every
+/// event in `record`, in `seq` order, for an `n`-player game. Used to
+/// verify a completed run, or to resume an in-progress one. `record`
+/// should be a [`merge`] of every player's and deck's log for the run, so
+/// events from different players are interleaved in the order they
+/// actually happened rather than just the order one file happened to list
+/// them in.
+///
+/// A record only logs the cards that moved between a deck and a hand, not
+/// a deck's undealt starting contents, so a drawn card may not already be
+/// present in its deck here (it's simply added to the drawing player's
+/// hand in that case).
+pub fn replay(record: &Record, n: usize) -> ReplayState {
+    let mut decks: Vec<Deck> = (1..=n).map(Deck::new).collect();
+    let mut hands: Vec<Vec<Card>> = vec![Vec::new(); n];
+
+    for (_, event) in &record.0 {
+        match event {
+            GameEvent::InitialHand { player, hand } => {
+                hands[player - 1] = hand.iter().map(|&v| Card(v)).collect();
+            }
+            GameEvent::Draw { player, card, from_deck } => {
+                let deck = &mut decks[from_deck - 1];
+                let drawn = match deck.cards.iter().position(|c| c.0 == *card) {
+                    Some(position) => deck.cards.remove(position).unwrap(),
+                    None => Card(*card),
+                };
+                hands[player - 1].push(drawn);
+            }
+            GameEvent::Discard { player, card, to_deck } => {
+                let hand = &mut hands[player - 1];
+                let position = hand
+                    .iter()
+                    .position(|c| c.0 == *card)
+                    .expect("record discarded a card that isn't in the player's hand");
+                let discarded = hand.remove(position);
+                decks[to_deck - 1].cards.push_back(discarded);
+            }
+            GameEvent::Win { .. } => {}
+        }
+    }
+
+    ReplayState { decks, hands }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_event_variant_through_a_line() {
+        let events = vec![
+            GameEvent::InitialHand { player: 1, hand: vec![1, 2, 3, 4] },
+            GameEvent::Draw { player: 1, card: 5, from_deck: 2 },
+            GameEvent::Discard { player: 1, card: 1, to_deck: 3 },
+            GameEvent::Win { player: 1, hand: vec![5, 2, 3, 4] },
+        ];
+        for (seq, event) in events.into_iter().enumerate() {
+            let line = format_line(seq, &event);
+            assert_eq!(parse_line(&line).unwrap(), (seq, event));
+        }
+    }
+
+    #[test]
+    fn parse_line_rejects_unknown_action() {
+        assert!(matches!(
+            parse_line("0 FOLD 1 2 3"),
+            Err(ParseRecordError::UnknownAction)
+        ));
+    }
+
+    #[test]
+    fn parse_line_rejects_a_missing_seq_prefix() {
+        assert!(matches!(
+            parse_line("INITIAL_HAND 1 1 2 3 4"),
+            Err(ParseRecordError::FailedToParseLine(_))
+        ));
+    }
+
+    #[test]
+    fn from_reader_skips_blank_lines() {
+        let log = "0 INITIAL_HAND 1 1 2 3 4\n\n1 DRAW 1 5 2\n";
+        let record = Record::from_reader(log.as_bytes()).unwrap();
+        assert_eq!(record.0.len(), 2);
+    }
+
+    #[test]
+    fn player_count_considers_deck_numbers_too() {
+        // As seen from player 1's own log alone: every event's `player`
+        // field is 1, but this player's draws/discards still reference
+        // deck 3, so the count must come out to 3, not 1.
+        let record = Record(vec![
+            (0, GameEvent::InitialHand { player: 1, hand: vec![1, 2, 3, 4] }),
+            (1, GameEvent::Draw { player: 1, card: 5, from_deck: 3 }),
+        ]);
+        assert_eq!(record.player_count(), 3);
+    }
+
+    #[test]
+    fn merge_deduplicates_the_same_event_seen_from_both_logs() {
+        let from_deck_log = Record(vec![(5, GameEvent::Draw { player: 1, card: 2, from_deck: 1 })]);
+        let from_player_log = Record(vec![(5, GameEvent::Draw { player: 1, card: 2, from_deck: 1 })]);
+        let merged = merge(vec![from_deck_log, from_player_log]);
+        assert_eq!(merged.0.len(), 1);
+    }
+
+    #[test]
+    fn merge_orders_events_by_seq_regardless_of_input_order() {
+        let a = Record(vec![(2, GameEvent::Win { player: 1, hand: vec![1, 1] })]);
+        let b = Record(vec![(0, GameEvent::InitialHand { player: 1, hand: vec![1, 2] })]);
+        let c = Record(vec![(1, GameEvent::Draw { player: 1, card: 1, from_deck: 2 })]);
+        let merged = merge(vec![a, b, c]);
+        let seqs: Vec<usize> = merged.0.iter().map(|(seq, _)| *seq).collect();
+        assert_eq!(seqs, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn replay_reconstructs_hands_and_decks_from_draws_and_discards() {
+        let record = Record(vec![
+            (0, GameEvent::InitialHand { player: 1, hand: vec![1, 2, 3, 4] }),
+            (1, GameEvent::Draw { player: 1, card: 5, from_deck: 2 }),
+            (2, GameEvent::Discard { player: 1, card: 1, to_deck: 3 }),
+        ]);
+        let state = replay(&record, 3);
+        assert_eq!(state.hands[0], vec![Card(2), Card(3), Card(4), Card(5)]);
+        assert_eq!(state.decks[2].cards, vec![Card(1)]);
+    }
+}
@@ -0,0 +1,144 @@
+use std::fs::File;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::card::Card;
+use crate::deck::Deck;
+use crate::error::GameError;
+
+/// A full snapshot of an in-progress game: every player's hand, every
+/// deck's contents, and which player's turn it would be next in a
+/// sequential game. Players actually run concurrently on their own
+/// threads (see [`crate::player::Player::play`]), so `next_player` is
+/// informational only — 0 once every player is racing to a winning hand
+/// with no single "next" turn, otherwise the player who had already won
+/// at the moment of the snapshot.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GameState {
+    pub player_count: usize,
+    pub next_player: usize,
+    pub hands: Vec<Vec<Card>>,
+    pub decks: Vec<Deck>,
+}
+
+impl GameState {
+    /// Takes a coherent snapshot of `decks` and `hands`. A card is only
+    /// ever "in transit" between a draw deck and a hand while
+    /// [`crate::player::Player::take_turn`] holds both of its deck locks
+    /// (see `lock_decks` there), so holding every deck lock at once here,
+    /// in the same ascending deck-number order turns use, blocks any
+    /// in-progress or about-to-start turn for the whole snapshot — no
+    /// drawn-but-not-yet-discarded card can be missing from both the deck
+    /// and hand lists. Hands are then locked the same way so none of them
+    /// changes mid-snapshot either.
+    pub fn snapshot(
+        decks: &[Arc<Mutex<Deck>>],
+        hands: &[Arc<Mutex<Vec<Card>>>],
+        next_player: usize,
+    ) -> GameState {
+        let deck_guards: Vec<_> = decks.iter().map(|deck| deck.lock().unwrap()).collect();
+        let hand_guards: Vec<_> = hands.iter().map(|hand| hand.lock().unwrap()).collect();
+
+        let decks: Vec<Deck> = deck_guards.iter().map(|guard| guard.snapshot()).collect();
+        let hands: Vec<Vec<Card>> = hand_guards.iter().map(|guard| (**guard).clone()).collect();
+
+        GameState {
+            player_count: hands.len(),
+            next_player,
+            hands,
+            decks,
+        }
+    }
+
+    /// Writes this snapshot to `path` as JSON.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), GameError> {
+        let file = File::create(path)?;
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+
+    /// Restores a snapshot previously written by [`GameState::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<GameState, GameError> {
+        let file = File::open(path)?;
+        let state = serde_json::from_reader(file)?;
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::*;
+
+    #[test]
+    fn snapshot_copies_every_deck_and_hand() {
+        let decks = vec![
+            Arc::new(Mutex::new(Deck {
+                number: 1,
+                cards: VecDeque::from(vec![Card(1)]),
+                log: None,
+            })),
+            Arc::new(Mutex::new(Deck {
+                number: 2,
+                cards: VecDeque::from(vec![Card(2)]),
+                log: None,
+            })),
+        ];
+        let hands = vec![
+            Arc::new(Mutex::new(vec![Card(1), Card(1)])),
+            Arc::new(Mutex::new(vec![Card(2), Card(2)])),
+        ];
+
+        let state = GameState::snapshot(&decks, &hands, 0);
+
+        assert_eq!(state.player_count, 2);
+        assert_eq!(state.next_player, 0);
+        assert_eq!(state.hands, vec![vec![Card(1), Card(1)], vec![Card(2), Card(2)]]);
+        assert_eq!(state.decks[0].cards, VecDeque::from(vec![Card(1)]));
+        assert_eq!(state.decks[1].cards, VecDeque::from(vec![Card(2)]));
+    }
+
+    #[test]
+    fn save_then_load_round_trips_a_snapshot() {
+        let state = GameState {
+            player_count: 2,
+            next_player: 1,
+            hands: vec![vec![Card(1), Card(1)], vec![Card(2), Card(3)]],
+            decks: vec![
+                Deck {
+                    number: 1,
+                    cards: VecDeque::from(vec![Card(4), Card(5)]),
+                    log: None,
+                },
+                Deck {
+                    number: 2,
+                    cards: VecDeque::new(),
+                    log: None,
+                },
+            ],
+        };
+
+        let path = std::env::temp_dir().join(format!(
+            "multithreaded-cards-rs-state-test-{}.json",
+            std::process::id()
+        ));
+        state.save(&path).unwrap();
+        let loaded = GameState::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.player_count, state.player_count);
+        assert_eq!(loaded.next_player, state.next_player);
+        assert_eq!(loaded.hands, state.hands);
+        assert_eq!(loaded.decks[0].cards, state.decks[0].cards);
+        assert_eq!(loaded.decks[1].cards, state.decks[1].cards);
+    }
+
+    #[test]
+    fn load_surfaces_an_error_for_a_missing_file() {
+        let result = GameState::load("/no/such/path/multithreaded-cards-rs.json");
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use rand::seq::SliceRandom;
+
+use crate::card::Card;
+
+/// Decides which card index a player discards at the end of a turn, or
+/// `None` to discard the card just drawn. Different players in the same
+/// game can use different strategies.
+pub trait DiscardStrategy: Send {
+    fn select(&self, hand: &[Card], player_number: usize) -> Option<usize>;
+}
+
+/// Discards a uniformly random card whose value isn't the player's own
+/// number. This was the crate's original, and only, behaviour.
+#[derive(Debug, Default)]
+pub struct RandomDiscard;
+
+impl DiscardStrategy for RandomDiscard {
+    fn select(&self, hand: &[Card], player_number: usize) -> Option<usize> {
+        let possibles = hand
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.0 != player_number)
+            .collect::<Vec<(usize, &Card)>>();
+
+        possibles.choose(&mut rand::thread_rng()).map(|&(index, _)| index)
+    }
+}
+
+/// Holds onto whichever value is most common in hand and discards the
+/// rarest foreign card, on the theory that the common value is closest to
+/// a winning hand.
+#[derive(Debug, Default)]
+pub struct HoldMostCommon;
+
+impl DiscardStrategy for HoldMostCommon {
+    fn select(&self, hand: &[Card], player_number: usize) -> Option<usize> {
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for card in hand {
+            *counts.entry(card.0).or_insert(0) += 1;
+        }
+
+        hand.iter()
+            .enumerate()
+            .filter(|(_, c)| c.0 != player_number)
+            .min_by_key(|(_, c)| counts[&c.0])
+            .map(|(index, _)| index)
+    }
+}
+
+/// Discards the numerically lowest card that isn't the player's own
+/// number.
+#[derive(Debug, Default)]
+pub struct LowestForeign;
+
+impl DiscardStrategy for LowestForeign {
+    fn select(&self, hand: &[Card], player_number: usize) -> Option<usize> {
+        hand.iter()
+            .enumerate()
+            .filter(|(_, c)| c.0 != player_number)
+            .min_by_key(|(_, c)| c.0)
+            .map(|(index, _)| index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_discard_never_picks_the_players_own_number() {
+        let hand = [Card(1), Card(1), Card(1), Card(1)];
+        assert_eq!(RandomDiscard.select(&hand, 1), None);
+    }
+
+    #[test]
+    fn random_discard_picks_a_foreign_card() {
+        let hand = [Card(1), Card(2), Card(1), Card(1)];
+        assert_eq!(RandomDiscard.select(&hand, 1), Some(1));
+    }
+
+    #[test]
+    fn hold_most_common_discards_the_rarest_foreign_card() {
+        let hand = [Card(1), Card(1), Card(2), Card(3)];
+        // 1 is most common but it's the player's own number, so it's kept
+        // regardless; between the foreign cards 2 and 3 (both count 1),
+        // the first one found by iteration order is discarded.
+        assert_eq!(HoldMostCommon.select(&hand, 1), Some(2));
+    }
+
+    #[test]
+    fn hold_most_common_returns_none_with_no_foreign_cards() {
+        let hand = [Card(1), Card(1)];
+        assert_eq!(HoldMostCommon.select(&hand, 1), None);
+    }
+
+    #[test]
+    fn lowest_foreign_discards_the_smallest_non_own_value() {
+        let hand = [Card(5), Card(2), Card(1), Card(3)];
+        assert_eq!(LowestForeign.select(&hand, 1), Some(1));
+    }
+
+    #[test]
+    fn lowest_foreign_returns_none_with_no_foreign_cards() {
+        let hand = [Card(1), Card(1)];
+        assert_eq!(LowestForeign.select(&hand, 1), None);
+    }
+}